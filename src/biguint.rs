@@ -0,0 +1,305 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Rem, Sub};
+
+// Minimal arbitrary-precision unsigned integer, stored as base 2^32 limbs,
+// least-significant limb first. `mfract` only needs add, mul, sub, pow, divmod
+// (for `%` and the final `/` in `get_norm`) and a decimal parser/printer,
+// so that's all this supports.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct BigUint {
+    limbs: Vec<u32>,
+}
+
+impl BigUint {
+    pub fn zero() -> Self {
+        BigUint{ limbs: vec![0] }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.iter().all(|&l| l == 0)
+    }
+
+    pub fn from_decimal_str(s: &str) -> Self {
+        let ten = BigUint::from(10_u32);
+        let mut n = BigUint::zero();
+
+        for c in s.chars() {
+            let d = c.to_digit(10).expect("from_decimal_str: non-digit character");
+            n = &(&n * &ten) + &BigUint::from(d);
+        }
+
+        n
+    }
+
+    pub fn pow(&self, mut exp: u32) -> Self {
+        let mut base = self.clone();
+        let mut result = BigUint::from(1_u32);
+
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = &result * &base;
+            }
+            base = &base * &base;
+            exp >>= 1;
+        }
+
+        result
+    }
+
+    fn trim(mut self) -> Self {
+        while self.limbs.len() > 1 && *self.limbs.last().unwrap() == 0 {
+            self.limbs.pop();
+        }
+
+        self
+    }
+
+    fn bit_len(&self) -> u32 {
+        let top = self.limbs.len() - 1;
+        top as u32 * 32 + (32 - self.limbs[top].leading_zeros())
+    }
+
+    fn get_bit(&self, i: u32) -> bool {
+        let idx = (i / 32) as usize;
+
+        if idx >= self.limbs.len() { false } else { (self.limbs[idx] >> (i % 32)) & 1 == 1 }
+    }
+
+    fn set_bit(&mut self, i: u32) {
+        let idx = (i / 32) as usize;
+
+        while self.limbs.len() <= idx {
+            self.limbs.push(0);
+        }
+
+        self.limbs[idx] |= 1 << (i % 32);
+    }
+
+    fn shl1(&self) -> Self {
+        let mut limbs = Vec::with_capacity(self.limbs.len() + 1);
+        let mut carry = 0_u32;
+
+        for &l in &self.limbs {
+            limbs.push((l << 1) | carry);
+            carry = l >> 31;
+        }
+
+        if carry != 0 {
+            limbs.push(carry);
+        }
+
+        BigUint{ limbs }.trim()
+    }
+}
+
+impl From<u32> for BigUint {
+    fn from(v: u32) -> Self {
+        BigUint{ limbs: vec![v] }
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self.limbs.len() != other.limbs.len() {
+            return self.limbs.len().cmp(&other.limbs.len());
+        }
+
+        for i in (0..self.limbs.len()).rev() {
+            if self.limbs[i] != other.limbs[i] {
+                return self.limbs[i].cmp(&other.limbs[i]);
+            }
+        }
+
+        Ordering::Equal
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'b> Add<&'b BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn add(self, rhs: &'b BigUint) -> BigUint {
+        let n = self.limbs.len().max(rhs.limbs.len());
+        let mut limbs = Vec::with_capacity(n + 1);
+        let mut carry: u64 = 0;
+
+        for i in 0..n {
+            let al = *self.limbs.get(i).unwrap_or(&0) as u64;
+            let bl = *rhs.limbs.get(i).unwrap_or(&0) as u64;
+            let sum = al + bl + carry;
+
+            limbs.push(sum as u32);
+            carry = sum >> 32;
+        }
+
+        if carry > 0 {
+            limbs.push(carry as u32);
+        }
+
+        BigUint{ limbs }.trim()
+    }
+}
+
+impl<'b> Sub<&'b BigUint> for &BigUint {
+    type Output = BigUint;
+
+    // Assumes self >= rhs, which always holds at every call site here
+    // (the long-division loop below, and `signed_add`, `abs_diff` and
+    // `best_approx` in main.rs, which order or bound their operands first).
+    fn sub(self, rhs: &'b BigUint) -> BigUint {
+        let mut limbs = Vec::with_capacity(self.limbs.len());
+        let mut borrow: i64 = 0;
+
+        for i in 0..self.limbs.len() {
+            let al = self.limbs[i] as i64;
+            let bl = *rhs.limbs.get(i).unwrap_or(&0) as i64;
+            let mut diff = al - bl - borrow;
+
+            if diff < 0 {
+                diff += 1_i64 << 32;
+                borrow = 1;
+            }
+            else {
+                borrow = 0;
+            }
+
+            limbs.push(diff as u32);
+        }
+
+        BigUint{ limbs }.trim()
+    }
+}
+
+impl<'b> Mul<&'b BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn mul(self, rhs: &'b BigUint) -> BigUint {
+        if self.is_zero() || rhs.is_zero() {
+            return BigUint::zero();
+        }
+
+        let mut limbs = vec![0_u32; self.limbs.len() + rhs.limbs.len()];
+
+        for (i, &al) in self.limbs.iter().enumerate() {
+            let mut carry: u64 = 0;
+
+            for (j, &bl) in rhs.limbs.iter().enumerate() {
+                let prod = al as u64 * bl as u64 + limbs[i + j] as u64 + carry;
+
+                limbs[i + j] = prod as u32;
+                carry = prod >> 32;
+            }
+
+            let mut k = i + rhs.limbs.len();
+
+            while carry > 0 {
+                let sum = limbs[k] as u64 + carry;
+
+                limbs[k] = sum as u32;
+                carry = sum >> 32;
+                k += 1;
+            }
+        }
+
+        BigUint{ limbs }.trim()
+    }
+}
+
+// Schoolbook binary long division: walk the dividend's bits from the top,
+// building up the remainder and recording a quotient bit whenever the
+// remainder is large enough to absorb another copy of the divisor.
+fn divmod(a: &BigUint, b: &BigUint) -> (BigUint, BigUint) {
+    assert!(!b.is_zero(), "divmod: division by zero");
+
+    let mut quotient = BigUint::zero();
+    let mut remainder = BigUint::zero();
+
+    for i in (0..a.bit_len()).rev() {
+        remainder = remainder.shl1();
+
+        if a.get_bit(i) {
+            remainder.set_bit(0);
+        }
+
+        if remainder >= *b {
+            remainder = &remainder - b;
+            quotient.set_bit(i);
+        }
+    }
+
+    (quotient, remainder)
+}
+
+impl<'b> Div<&'b BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn div(self, rhs: &'b BigUint) -> BigUint {
+        divmod(self, rhs).0
+    }
+}
+
+impl<'b> Rem<&'b BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn rem(self, rhs: &'b BigUint) -> BigUint {
+        divmod(self, rhs).1
+    }
+}
+
+impl fmt::Display for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+
+        let ten = BigUint::from(10_u32);
+        let mut n = self.clone();
+        let mut digits = Vec::new();
+
+        while !n.is_zero() {
+            let (q, r) = divmod(&n, &ten);
+
+            digits.push(char::from_digit(r.limbs[0], 10).unwrap());
+            n = q;
+        }
+
+        let s: String = digits.iter().rev().collect();
+        write!(f, "{}", s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_0010() {
+        let a = BigUint::from_decimal_str("100000000000000000000");
+        let b = BigUint::from(3_u32);
+
+        assert_eq!((&a / &b).to_string(), "33333333333333333333");
+        assert_eq!((&a % &b).to_string(), "1");
+    }
+
+    #[test]
+    fn test_0020() {
+        let a = BigUint::from_decimal_str("340282366920938463463374607431768211456"); // 2^128
+        let b = BigUint::from(1_u32);
+
+        assert_eq!((&a - &b).to_string(), "340282366920938463463374607431768211455");
+    }
+
+    #[test]
+    fn test_0030() {
+        let a = BigUint::from_decimal_str("123456789012345678901234567890");
+        let b = BigUint::from_decimal_str("987654321");
+
+        assert_eq!((&(&(&a / &b) * &b) + &(&a % &b)).to_string(), a.to_string());
+    }
+}
@@ -1,258 +1,774 @@
-use lazy_static::lazy_static;
-use regex::Regex;
-use std::env::args;
-use std::process::ExitCode;
-
-struct MyNum {
-    mnt: u64,
-    exp: u8,
-}
-
-#[derive(PartialEq, Debug)]
-struct Fract {
-    numer: u64,
-    denom: u64,
-}
-
-enum FType {
-    Num,
-    Den,
-}
-
-fn main() -> ExitCode {
-    if args().len() <= 1 {
-        eprintln!("E{:04}: {}", 10, "No argument found");
-        return ExitCode::from(10);
-    }
-
-    if args().len() != 2 {
-        eprintln!("E{:04}: {}", 12, format!("Too many arguments ({})", args().len() - 1));
-        return ExitCode::from(12);
-    }
-
-    let my_arg = args().skip(1).next().unwrap();
-    let my_opt = get_fract(&my_arg);
-
-    if let Err((ecd, emsg)) = my_opt {
-        eprintln!("E{:04}: {}", ecd, emsg);
-        return ExitCode::from(ecd);
-    }
-
-    let my_mfr = my_opt.unwrap();
-
-    println!("{}/{}", my_mfr.numer, my_mfr.denom);
-    ExitCode::SUCCESS
-}
-
-fn get_fract(inp_fract: &String) -> Result<Fract, (u8, String)> {
-    lazy_static! { static ref RX_FRACT1: Regex = Regex::new(r"(?xms)\A ([^/]+)           \z").unwrap(); }
-    lazy_static! { static ref RX_FRACT2: Regex = Regex::new(r"(?xms)\A ([^/]+) / ([^/]+) \z").unwrap(); }
-
-    let (inp_num, inp_den);
-
-    if let Some(s) = RX_FRACT1.captures(&inp_fract) {
-        inp_num = s[1].to_string();
-        inp_den = "1".to_string();
-    }
-    else if let Some(s) = RX_FRACT2.captures(&inp_fract) {
-        inp_num = s[1].to_string();
-        inp_den = s[2].to_string();
-    }
-    else {
-        return Err((14, format!("Could not parse fraction")));
-    }
-
-    let val_num = get_num(FType::Num, &inp_num)?;
-    let val_den = get_num(FType::Den, &inp_den)?;
-
-    let exp_p10 = val_num.exp.abs_diff(val_den.exp);
-
-    let val_p10 =
-      10_u64.checked_pow(exp_p10.into()).
-      ok_or((16, format!("p10 overflow for 10 ^ {}", exp_p10)))?;
-
-    let mfr_dat =
-        if val_num.exp > val_den.exp {
-            let tmp_den =
-              val_den.mnt.checked_mul(val_p10).
-              ok_or((18, format!("Denominator overflow: {} * {}", val_den.mnt, val_p10)))?;
-
-            Fract{ numer: val_num.mnt, denom: tmp_den }
-        }
-        else {
-            let tmp_num =
-              val_num.mnt.checked_mul(val_p10).
-              ok_or((20, format!("Numerator overflow: {} * {}", val_den.mnt, val_p10)))?;
-
-            Fract{ numer: tmp_num, denom: val_den.mnt }
-        };
-
-    Ok(get_norm(&mfr_dat)?)
-}
-
-fn get_num(p_type: FType, p_str: &String) -> Result<MyNum, (u8, String)> {
-    let p_label = match p_type { FType::Num => "Numerator", FType::Den => "Denominator" };
-
-    lazy_static! { static ref RX_NUM1: Regex = Regex::new(r"(?xms)\A \d+               \z").unwrap(); }
-    lazy_static! { static ref RX_NUM2: Regex = Regex::new(r"(?xms)\A (\d+) [,\.] (\d+) \z").unwrap(); }
-
-    let gn_str: String;
-    let gn_exp: u8;
-
-    if RX_NUM1.find(&p_str).is_some() {
-        gn_str = p_str.to_string();
-        gn_exp = 0;
-    }
-    else if let Some(s) = RX_NUM2.captures(&p_str) {
-        let p1 = s[1].to_string();
-        let p2 = s[2].to_string();
-
-        gn_str = p1 + &p2;
-        gn_exp = u8::try_from(p2.len()).unwrap_or(0);
-    }
-    else {
-        return Err((22, format!("Can't parse {} = '{}'", p_label, p_str)));
-    }
-
-    let gn_mnt =
-      gn_str.parse::<u64>().
-      map_err(|_| (24, format!("Integer overflow {} = '{}'", p_label, p_str)))?;
-
-    Ok(MyNum{ mnt: gn_mnt, exp: gn_exp })
-}
-
-fn get_norm(fr: &Fract) -> Result<Fract, (u8, String)> {
-    if fr.denom == 0 {
-        return Err((26, "Division by zero".to_string()));
-    }
-
-    if fr.numer == 0 {
-        return Ok(Fract{ numer: 0, denom: 1 });
-    }
-
-    // Calculate gcd using the Euclidean algorithm
-    // https://en.wikipedia.org/wiki/Euclidean_algorithm
-
-    let mut xa = fr.numer;
-    let mut xb = fr.denom;
-
-    while xb > 0 {
-        let tmp = xb;
-        xb = xa % xb;
-        xa = tmp;
-    }
-
-    Ok(Fract{ numer: fr.numer / xa, denom: fr.denom / xa })
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_0010() {
-        let result = get_norm(&Fract{ numer: 486, denom: 12 });
-        assert_eq!(result, Ok(Fract{ numer: 81, denom: 2 }));
-    }
-
-    #[test]
-    fn test_0020() {
-        let result = get_norm(&Fract{ numer: 96, denom: 4 });
-        assert_eq!(result, Ok(Fract{ numer: 24, denom: 1 }));
-    }
-
-    #[test]
-    fn test_0030() {
-        let result = get_norm(&Fract{ numer: 0, denom: 3 });
-        assert_eq!(result, Ok(Fract{ numer: 0, denom: 1 }));
-    }
-
-    #[test]
-    fn test_0040() {
-        let result = get_fract(&"3/10000000000000000000".to_string());
-
-        if let Ok(_) = result {
-        }
-        else {
-            assert!(false);
-        }
-    }
-
-    #[test]
-    fn test_0050() {
-        let result = get_fract(&"35,6/12".to_string());
-        assert_eq!(result, Ok(Fract{ numer: 89, denom: 30 }));
-    }
-
-    #[test]
-    fn test_0060() {
-        let result = get_fract(&"0,000000000000001/1000000000000000000".to_string());
-
-        if let Err((ecd, _)) = result {
-            assert_eq!(ecd, 18); // Denominator overflow
-        }
-        else {
-            assert!(false);
-        }
-    }
-
-    #[test]
-    fn test_0070() {
-        let result = get_fract(&"1000000000000000000/0,000000000000001".to_string());
-
-        if let Err((ecd, _)) = result {
-            assert_eq!(ecd, 20); // Numerator overflow
-        }
-        else {
-            assert!(false);
-        }
-    }
-
-    #[test]
-    fn test_0080() {
-        let result = get_fract(&"smdjfklsjkdf".to_string());
-
-        if let Err((ecd, _)) = result {
-            assert_eq!(ecd, 22); // Can't parse
-        }
-        else {
-            assert!(false);
-        }
-    }
-
-    #[test]
-    fn test_0090() {
-        let result = get_fract(&"100000000000000000000/3".to_string());
-
-        if let Err((ecd, _)) = result {
-            assert_eq!(ecd, 24); // Integer overflow Numerator
-        }
-        else {
-            assert!(false);
-        }
-    }
-
-    #[test]
-    fn test_0100() {
-        let result = get_fract(&"3/100000000000000000000".to_string());
-
-        if let Err((ecd, _)) = result {
-            assert_eq!(ecd, 24); // Integer overflow Denominator
-        }
-        else {
-            assert!(false);
-        }
-    }
-
-    #[test]
-    fn test_0110() {
-        let result = get_norm(&Fract{ numer: 0, denom: 0 });
-
-        if let Err((ecd, _)) = result {
-            assert_eq!(ecd, 26); // Division by zero
-        }
-        else {
-            assert!(false);
-        }
-    }
-}
+mod biguint;
+
+use biguint::BigUint;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::env::args;
+use std::process::ExitCode;
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+enum Sign {
+    Positive,
+    Negative,
+}
+
+impl Sign {
+    fn xor(self, other: Sign) -> Sign {
+        if self == other { Sign::Positive } else { Sign::Negative }
+    }
+
+    fn negate(self) -> Sign {
+        match self { Sign::Positive => Sign::Negative, Sign::Negative => Sign::Positive }
+    }
+}
+
+struct MyNum {
+    sign: Sign,
+    mnt: BigUint,
+    exp: u8,
+}
+
+#[derive(PartialEq, Debug)]
+struct Fract {
+    sign: Sign,
+    numer: BigUint,
+    denom: BigUint,
+}
+
+enum FType {
+    Num,
+    Den,
+}
+
+enum FracOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+fn main() -> ExitCode {
+    if args().len() <= 1 {
+        eprintln!("E{:04}: {}", 10, "No argument found");
+        return ExitCode::from(10);
+    }
+
+    if args().len() > 3 {
+        eprintln!("E{:04}: {}", 12, format!("Too many arguments ({})", args().len() - 1));
+        return ExitCode::from(12);
+    }
+
+    let mut my_args = args().skip(1);
+    let my_arg = my_args.next().unwrap();
+    let my_mode = my_args.next();
+    let mixed_mode = my_mode.as_deref() == Some("mixed");
+
+    let my_opt = get_expr(&my_arg).and_then(|fr| {
+        if mixed_mode {
+            Ok(fr)
+        }
+        else if let Some(md_arg) = &my_mode {
+            get_max_denom(md_arg).map(|md| best_approx(&fr, &md))
+        }
+        else {
+            Ok(fr)
+        }
+    });
+
+    if let Err((ecd, emsg)) = my_opt {
+        eprintln!("E{:04}: {}", ecd, emsg);
+        return ExitCode::from(ecd);
+    }
+
+    let my_mfr = my_opt.unwrap();
+
+    if mixed_mode {
+        println!("{}", format_mixed(&my_mfr));
+    }
+    else {
+        let sign_str = if my_mfr.sign == Sign::Negative { "-" } else { "" };
+        println!("{}{}/{}", sign_str, my_mfr.numer, my_mfr.denom);
+    }
+    ExitCode::SUCCESS
+}
+
+// Renders a normalized fraction as a mixed number, e.g. 89/30 -> "2 29/30".
+// Pure integers print with no fractional part, and zero prints as "0".
+fn format_mixed(fr: &Fract) -> String {
+    let sign_str = if fr.sign == Sign::Negative { "-" } else { "" };
+
+    let whole = &fr.numer / &fr.denom;
+    let rem = &fr.numer % &fr.denom;
+
+    if rem.is_zero() {
+        format!("{}{}", sign_str, whole)
+    }
+    else if whole.is_zero() {
+        format!("{}{}/{}", sign_str, rem, fr.denom)
+    }
+    else {
+        format!("{}{} {}/{}", sign_str, whole, rem, fr.denom)
+    }
+}
+
+// Single-codepoint vulgar fractions, read as complete fractions on their own.
+fn vulgar_fraction(c: char) -> Option<(&'static str, &'static str)> {
+    match c {
+        '½' => Some(("1", "2")),
+        '⅓' => Some(("1", "3")),
+        '⅔' => Some(("2", "3")),
+        '¼' => Some(("1", "4")),
+        '¾' => Some(("3", "4")),
+        '⅐' => Some(("1", "7")),
+        '⅛' => Some(("1", "8")),
+        _ => None,
+    }
+}
+
+fn from_superscript(s: &str) -> String {
+    s.chars().map(|c| match c {
+        '⁰' => '0', '¹' => '1', '²' => '2', '³' => '3', '⁴' => '4',
+        '⁵' => '5', '⁶' => '6', '⁷' => '7', '⁸' => '8', '⁹' => '9',
+        other => other,
+    }).collect()
+}
+
+fn from_subscript(s: &str) -> String {
+    s.chars().map(|c| match c {
+        '₀' => '0', '₁' => '1', '₂' => '2', '₃' => '3', '₄' => '4',
+        '₅' => '5', '₆' => '6', '₇' => '7', '₈' => '8', '₉' => '9',
+        other => other,
+    }).collect()
+}
+
+// Folds a leading ASCII whole number into an improper fraction over the
+// given numerator/denominator, e.g. ("", "1", "1", "2") -> "3/2" and
+// ("-", "1", "1", "2") -> "-3/2" (reading "-1½" as -(1 + 1/2)).
+fn fold_mixed(sign_str: &str, whole_str: &str, n: &str, d: &str) -> String {
+    if whole_str.is_empty() {
+        format!("{}{}/{}", sign_str, n, d)
+    }
+    else {
+        let w = BigUint::from_decimal_str(whole_str);
+        let nn = BigUint::from_decimal_str(n);
+        let dd = BigUint::from_decimal_str(d);
+        let combined = &(&w * &dd) + &nn;
+
+        format!("{}{}/{}", sign_str, combined, d)
+    }
+}
+
+fn get_fract(inp_fract: &String) -> Result<Fract, (u8, String)> {
+    lazy_static! { static ref RX_FRACT1: Regex = Regex::new(r"(?xms)\A (-)? ([^/]+)           \z").unwrap(); }
+    lazy_static! { static ref RX_FRACT2: Regex = Regex::new(r"(?xms)\A (-)? ([^/]+) / ([^/]+) \z").unwrap(); }
+
+    // An optional ASCII whole number followed by a single vulgar fraction
+    // character, e.g. "1½", read as a mixed number.
+    lazy_static! { static ref RX_VULGAR: Regex = Regex::new(r"(?xms)\A (-)? ([0-9]*) ([½⅓⅔¼¾⅐⅛]) \z").unwrap(); }
+
+    // An optional ASCII whole number followed by a superscript-numerator /
+    // fraction-slash / subscript-denominator run, e.g. "1³⁄₄".
+    lazy_static! { static ref RX_SUPERSUB: Regex = Regex::new(r"(?xms)\A (-)? ([0-9]*) ([⁰¹²³⁴⁵⁶⁷⁸⁹]+) ⁄ ([₀₁₂₃₄₅₆₇₈₉]+) \z").unwrap(); }
+
+    // A plain ASCII "whole num/den" mixed number, e.g. "1 1/2".
+    lazy_static! { static ref RX_MIXED: Regex = Regex::new(r"(?xms)\A (-)? ([0-9]+) \s+ ([0-9]+) / ([0-9]+) \z").unwrap(); }
+
+    let folded;
+
+    let working: &str =
+        if let Some(s) = RX_VULGAR.captures(inp_fract) {
+            let sign_str = s.get(1).map_or("", |m| m.as_str());
+            let (n, d) = vulgar_fraction(s[3].chars().next().unwrap()).unwrap();
+
+            folded = fold_mixed(sign_str, &s[2], n, d);
+            &folded
+        }
+        else if let Some(s) = RX_SUPERSUB.captures(inp_fract) {
+            let sign_str = s.get(1).map_or("", |m| m.as_str());
+            let n = from_superscript(&s[3]);
+            let d = from_subscript(&s[4]);
+
+            folded = fold_mixed(sign_str, &s[2], &n, &d);
+            &folded
+        }
+        else if let Some(s) = RX_MIXED.captures(inp_fract) {
+            let sign_str = s.get(1).map_or("", |m| m.as_str());
+
+            folded = fold_mixed(sign_str, &s[2], &s[3], &s[4]);
+            &folded
+        }
+        else {
+            inp_fract.as_str()
+        };
+
+    let (whole_sign, inp_num, inp_den);
+
+    if let Some(s) = RX_FRACT1.captures(working) {
+        whole_sign = if s.get(1).is_some() { Sign::Negative } else { Sign::Positive };
+        inp_num = s[2].to_string();
+        inp_den = "1".to_string();
+    }
+    else if let Some(s) = RX_FRACT2.captures(working) {
+        whole_sign = if s.get(1).is_some() { Sign::Negative } else { Sign::Positive };
+        inp_num = s[2].to_string();
+        inp_den = s[3].to_string();
+    }
+    else {
+        return Err((14, format!("Could not parse fraction")));
+    }
+
+    let val_num = get_num(FType::Num, &inp_num)?;
+    let val_den = get_num(FType::Den, &inp_den)?;
+
+    let mfr_sign = whole_sign.xor(val_num.sign).xor(val_den.sign);
+
+    let exp_p10 = val_num.exp.abs_diff(val_den.exp);
+    let val_p10 = BigUint::from(10_u32).pow(exp_p10.into());
+
+    let mfr_dat =
+        if val_num.exp > val_den.exp {
+            let tmp_den = &val_den.mnt * &val_p10;
+
+            Fract{ sign: mfr_sign, numer: val_num.mnt, denom: tmp_den }
+        }
+        else {
+            let tmp_num = &val_num.mnt * &val_p10;
+
+            Fract{ sign: mfr_sign, numer: tmp_num, denom: val_den.mnt }
+        };
+
+    Ok(get_norm(&mfr_dat)?)
+}
+
+// Evaluates an expression of one or more fractions joined by `+ - * /`
+// (`×` and `÷` are also accepted), e.g. "1/2 + 3/4" or "35,6/12 * 2".
+// Operators must be set off by whitespace so they can't be confused with
+// the `/` that separates a term's own numerator and denominator.
+fn get_expr(inp_expr: &String) -> Result<Fract, (u8, String)> {
+    let (terms, ops) = tokenize(inp_expr);
+
+    let mut acc = get_fract(&terms[0])?;
+
+    for (op, term) in ops.iter().zip(terms.iter().skip(1)) {
+        let rhs = get_fract(term)?;
+        acc = apply_op(&acc, op, &rhs)?;
+    }
+
+    Ok(acc)
+}
+
+fn tokenize(inp_expr: &str) -> (Vec<String>, Vec<FracOp>) {
+    lazy_static! { static ref RX_OP: Regex = Regex::new(r"(?x) \s+ ([+\-*/×÷]) (?: \s+ | \z )").unwrap(); }
+
+    let mut terms = Vec::new();
+    let mut ops = Vec::new();
+    let mut last = 0;
+
+    for cap in RX_OP.captures_iter(inp_expr) {
+        let m = cap.get(0).unwrap();
+
+        terms.push(inp_expr[last..m.start()].trim().to_string());
+        ops.push(match &cap[1] {
+            "+" => FracOp::Add,
+            "-" => FracOp::Sub,
+            "*" | "×" => FracOp::Mul,
+            "/" | "÷" => FracOp::Div,
+            _ => unreachable!(),
+        });
+        last = m.end();
+    }
+
+    terms.push(inp_expr[last..].trim().to_string());
+
+    (terms, ops)
+}
+
+fn apply_op(lhs: &Fract, op: &FracOp, rhs: &Fract) -> Result<Fract, (u8, String)> {
+    match op {
+        FracOp::Add => frac_add(lhs, rhs),
+        FracOp::Sub => frac_add(lhs, &Fract{ sign: rhs.sign.negate(), numer: rhs.numer.clone(), denom: rhs.denom.clone() }),
+        FracOp::Mul => frac_mul(lhs, rhs),
+        FracOp::Div => frac_div(lhs, rhs),
+    }
+}
+
+// a/b + c/d = (a*d + c*b) / (b*d)
+fn frac_add(a: &Fract, b: &Fract) -> Result<Fract, (u8, String)> {
+    let lhs_numer = &a.numer * &b.denom;
+    let rhs_numer = &b.numer * &a.denom;
+    let denom = &a.denom * &b.denom;
+
+    let (sign, numer) = signed_add(a.sign, &lhs_numer, b.sign, &rhs_numer);
+
+    get_norm(&Fract{ sign, numer, denom })
+}
+
+// (a*c) / (b*d)
+fn frac_mul(a: &Fract, b: &Fract) -> Result<Fract, (u8, String)> {
+    get_norm(&Fract{ sign: a.sign.xor(b.sign), numer: &a.numer * &b.numer, denom: &a.denom * &b.denom })
+}
+
+// Divide by the reciprocal: (a/b) / (c/d) = (a*d) / (b*c)
+fn frac_div(a: &Fract, b: &Fract) -> Result<Fract, (u8, String)> {
+    if b.numer.is_zero() {
+        return Err((26, "Division by zero".to_string()));
+    }
+
+    get_norm(&Fract{ sign: a.sign.xor(b.sign), numer: &a.numer * &b.denom, denom: &a.denom * &b.numer })
+}
+
+// Adds two signed magnitudes, picking the sign of whichever is larger when
+// they disagree (so the result is never constructed as a negative BigUint).
+fn signed_add(s1: Sign, m1: &BigUint, s2: Sign, m2: &BigUint) -> (Sign, BigUint) {
+    if s1 == s2 {
+        (s1, m1 + m2)
+    }
+    else if m1 >= m2 {
+        (s1, m1 - m2)
+    }
+    else {
+        (s2, m2 - m1)
+    }
+}
+
+fn get_max_denom(p_str: &str) -> Result<BigUint, (u8, String)> {
+    lazy_static! { static ref RX_MAXDEN: Regex = Regex::new(r"(?xms)\A (\d+) \z").unwrap(); }
+
+    if !RX_MAXDEN.is_match(p_str) {
+        return Err((28, format!("Can't parse max denominator = '{}'", p_str)));
+    }
+
+    let md = BigUint::from_decimal_str(p_str);
+
+    if md.is_zero() {
+        return Err((28, format!("Max denominator must be positive: '{}'", p_str)));
+    }
+
+    Ok(md)
+}
+
+// Best rational approximation of `fr` with denominator <= `max_denom`,
+// via the continued-fraction / Stern-Brocot convergents of numer/denom.
+// Coefficients a_k come from the Euclidean algorithm; convergents follow
+// p_k = a_k*p_{k-1} + p_{k-2}, q_k = a_k*q_{k-1} + q_{k-2}, seeded with
+// p_{-1}=1, q_{-1}=0, p_{-2}=0, q_{-2}=1. Once a convergent would exceed
+// max_denom, the best candidate is either the previous convergent or the
+// semiconvergent p_{k-2} + t*p_{k-1} over q_{k-2} + t*q_{k-1} for the
+// largest t keeping the denominator within bound; whichever is closer to
+// the original value (compared via cross-multiplication) wins.
+fn best_approx(fr: &Fract, max_denom: &BigUint) -> Fract {
+    let orig_numer = fr.numer.clone();
+    let orig_denom = fr.denom.clone();
+
+    let mut num = orig_numer.clone();
+    let mut den = orig_denom.clone();
+
+    let mut p2 = BigUint::from(0_u32);
+    let mut q2 = BigUint::from(1_u32);
+    let mut p1 = BigUint::from(1_u32);
+    let mut q1 = BigUint::from(0_u32);
+
+    loop {
+        if den.is_zero() {
+            break;
+        }
+
+        let a = &num / &den;
+        let rem = &num % &den;
+
+        let p_cur = &(&a * &p1) + &p2;
+        let q_cur = &(&a * &q1) + &q2;
+
+        if q_cur > *max_denom {
+            let t = &(max_denom - &q2) / &q1;
+            let p_semi = &(&t * &p1) + &p2;
+            let q_semi = &(&t * &q1) + &q2;
+
+            let err_conv = &abs_diff(&(&p1 * &orig_denom), &(&orig_numer * &q1)) * &q_semi;
+            let err_semi = &abs_diff(&(&p_semi * &orig_denom), &(&orig_numer * &q_semi)) * &q1;
+
+            return if err_semi <= err_conv {
+                Fract{ sign: fr.sign, numer: p_semi, denom: q_semi }
+            }
+            else {
+                Fract{ sign: fr.sign, numer: p1, denom: q1 }
+            };
+        }
+
+        p2 = p1;
+        q2 = q1;
+        p1 = p_cur;
+        q1 = q_cur;
+
+        num = den;
+        den = rem;
+    }
+
+    Fract{ sign: fr.sign, numer: p1, denom: q1 }
+}
+
+fn abs_diff(a: &BigUint, b: &BigUint) -> BigUint {
+    if a >= b { a - b } else { b - a }
+}
+
+fn get_num(p_type: FType, p_str: &String) -> Result<MyNum, (u8, String)> {
+    let p_label = match p_type { FType::Num => "Numerator", FType::Den => "Denominator" };
+
+    lazy_static! { static ref RX_NUM1: Regex = Regex::new(r"(?xms)\A (-)? (\d+)               \z").unwrap(); }
+    lazy_static! { static ref RX_NUM2: Regex = Regex::new(r"(?xms)\A (-)? (\d+) [,\.] (\d+) \z").unwrap(); }
+
+    let gn_str: String;
+    let gn_exp: u8;
+    let gn_sign: Sign;
+
+    if let Some(s) = RX_NUM1.captures(&p_str) {
+        gn_sign = if s.get(1).is_some() { Sign::Negative } else { Sign::Positive };
+        gn_str = s[2].to_string();
+        gn_exp = 0;
+    }
+    else if let Some(s) = RX_NUM2.captures(&p_str) {
+        gn_sign = if s.get(1).is_some() { Sign::Negative } else { Sign::Positive };
+        let p1 = s[2].to_string();
+        let p2 = s[3].to_string();
+
+        gn_str = p1 + &p2;
+        gn_exp = u8::try_from(p2.len()).unwrap_or(0);
+    }
+    else {
+        return Err((22, format!("Can't parse {} = '{}'", p_label, p_str)));
+    }
+
+    let gn_mnt = BigUint::from_decimal_str(&gn_str);
+
+    Ok(MyNum{ sign: gn_sign, mnt: gn_mnt, exp: gn_exp })
+}
+
+fn get_norm(fr: &Fract) -> Result<Fract, (u8, String)> {
+    if fr.denom.is_zero() {
+        return Err((26, "Division by zero".to_string()));
+    }
+
+    if fr.numer.is_zero() {
+        return Ok(Fract{ sign: Sign::Positive, numer: BigUint::zero(), denom: BigUint::from(1_u32) });
+    }
+
+    // Calculate gcd using the Euclidean algorithm
+    // https://en.wikipedia.org/wiki/Euclidean_algorithm
+
+    let mut xa = fr.numer.clone();
+    let mut xb = fr.denom.clone();
+
+    while !xb.is_zero() {
+        let tmp = xb.clone();
+        xb = &xa % &xb;
+        xa = tmp;
+    }
+
+    Ok(Fract{ sign: fr.sign, numer: &fr.numer / &xa, denom: &fr.denom / &xa })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn big(s: &str) -> BigUint {
+        BigUint::from_decimal_str(s)
+    }
+
+    #[test]
+    fn test_0010() {
+        let result = get_norm(&Fract{ sign: Sign::Positive, numer: big("486"), denom: big("12") });
+        assert_eq!(result, Ok(Fract{ sign: Sign::Positive, numer: big("81"), denom: big("2") }));
+    }
+
+    #[test]
+    fn test_0020() {
+        let result = get_norm(&Fract{ sign: Sign::Positive, numer: big("96"), denom: big("4") });
+        assert_eq!(result, Ok(Fract{ sign: Sign::Positive, numer: big("24"), denom: big("1") }));
+    }
+
+    #[test]
+    fn test_0030() {
+        let result = get_norm(&Fract{ sign: Sign::Positive, numer: big("0"), denom: big("3") });
+        assert_eq!(result, Ok(Fract{ sign: Sign::Positive, numer: big("0"), denom: big("1") }));
+    }
+
+    #[test]
+    fn test_0040() {
+        let result = get_fract(&"3/10000000000000000000".to_string());
+
+        if let Ok(_) = result {
+        }
+        else {
+            assert!(false);
+        }
+    }
+
+    #[test]
+    fn test_0050() {
+        let result = get_fract(&"35,6/12".to_string());
+        assert_eq!(result, Ok(Fract{ sign: Sign::Positive, numer: big("89"), denom: big("30") }));
+    }
+
+    #[test]
+    fn test_0060() {
+        // No longer a denominator overflow now that the backend is arbitrary precision.
+        let result = get_fract(&"0,000000000000001/1000000000000000000".to_string());
+        assert_eq!(result, Ok(Fract{ sign: Sign::Positive, numer: big("1"), denom: big("1000000000000000000000000000000000") }));
+    }
+
+    #[test]
+    fn test_0070() {
+        // No longer a numerator overflow now that the backend is arbitrary precision.
+        let result = get_fract(&"1000000000000000000/0,000000000000001".to_string());
+        assert_eq!(result, Ok(Fract{ sign: Sign::Positive, numer: big("1000000000000000000000000000000000"), denom: big("1") }));
+    }
+
+    #[test]
+    fn test_0080() {
+        let result = get_fract(&"smdjfklsjkdf".to_string());
+
+        if let Err((ecd, _)) = result {
+            assert_eq!(ecd, 22); // Can't parse
+        }
+        else {
+            assert!(false);
+        }
+    }
+
+    #[test]
+    fn test_0090() {
+        // No longer an integer overflow now that the backend is arbitrary precision.
+        let result = get_fract(&"100000000000000000000/3".to_string());
+        assert_eq!(result, Ok(Fract{ sign: Sign::Positive, numer: big("100000000000000000000"), denom: big("3") }));
+    }
+
+    #[test]
+    fn test_0100() {
+        // No longer an integer overflow now that the backend is arbitrary precision.
+        let result = get_fract(&"3/100000000000000000000".to_string());
+        assert_eq!(result, Ok(Fract{ sign: Sign::Positive, numer: big("3"), denom: big("100000000000000000000") }));
+    }
+
+    #[test]
+    fn test_0110() {
+        let result = get_norm(&Fract{ sign: Sign::Positive, numer: big("0"), denom: big("0") });
+
+        if let Err((ecd, _)) = result {
+            assert_eq!(ecd, 26); // Division by zero
+        }
+        else {
+            assert!(false);
+        }
+    }
+
+    #[test]
+    fn test_0120() {
+        let result = get_fract(&"-3/4".to_string());
+        assert_eq!(result, Ok(Fract{ sign: Sign::Negative, numer: big("3"), denom: big("4") }));
+    }
+
+    #[test]
+    fn test_0130() {
+        let result = get_fract(&"5/-8".to_string());
+        assert_eq!(result, Ok(Fract{ sign: Sign::Negative, numer: big("5"), denom: big("8") }));
+    }
+
+    #[test]
+    fn test_0140() {
+        // Double negative cancels out: -a/-b is positive
+        let result = get_fract(&"-3/-4".to_string());
+        assert_eq!(result, Ok(Fract{ sign: Sign::Positive, numer: big("3"), denom: big("4") }));
+    }
+
+    #[test]
+    fn test_0150() {
+        // -0 normalizes to a plain, positive zero
+        let result = get_fract(&"-0/5".to_string());
+        assert_eq!(result, Ok(Fract{ sign: Sign::Positive, numer: big("0"), denom: big("1") }));
+    }
+
+    #[test]
+    fn test_0160() {
+        // Deeply nested decimals (more digits than fit in a u64) still normalize
+        // correctly; check by cross-multiplying against the un-reduced ratio.
+        let result = get_fract(&"1,23456789012345678901234567890/2".to_string()).unwrap();
+
+        let orig_numer = big("123456789012345678901234567890");
+        let orig_denom = &big("2") * &BigUint::from(10_u32).pow(29);
+
+        assert_eq!(&result.numer * &orig_denom, &orig_numer * &result.denom);
+    }
+
+    #[test]
+    fn test_0170() {
+        let result = get_expr(&"1/2 + 3/4".to_string());
+        assert_eq!(result, Ok(Fract{ sign: Sign::Positive, numer: big("5"), denom: big("4") }));
+    }
+
+    #[test]
+    fn test_0180() {
+        let result = get_expr(&"35,6/12 * 2".to_string());
+        assert_eq!(result, Ok(Fract{ sign: Sign::Positive, numer: big("89"), denom: big("15") }));
+    }
+
+    #[test]
+    fn test_0190() {
+        // Left-to-right evaluation across more than two terms.
+        let result = get_expr(&"1/2 - 1/4 + 1/4".to_string());
+        assert_eq!(result, Ok(Fract{ sign: Sign::Positive, numer: big("1"), denom: big("2") }));
+    }
+
+    #[test]
+    fn test_0200() {
+        let result = get_expr(&"1/2 ÷ 4".to_string());
+        assert_eq!(result, Ok(Fract{ sign: Sign::Positive, numer: big("1"), denom: big("8") }));
+    }
+
+    #[test]
+    fn test_0210() {
+        let result = get_expr(&"1/0 + 1/2".to_string());
+
+        if let Err((ecd, _)) = result {
+            assert_eq!(ecd, 26); // Division by zero
+        }
+        else {
+            assert!(false);
+        }
+    }
+
+    #[test]
+    fn test_0220() {
+        let result = get_expr(&"1/2 +".to_string());
+
+        if let Err((ecd, _)) = result {
+            assert_eq!(ecd, 14); // Could not parse fraction (trailing, empty term)
+        }
+        else {
+            assert!(false);
+        }
+    }
+
+    #[test]
+    fn test_0230() {
+        // 100/3 = 33.33..., closest fraction with denominator <= 1 is 33/1.
+        let result = best_approx(&Fract{ sign: Sign::Positive, numer: big("100"), denom: big("3") }, &big("1"));
+        assert_eq!(result, Fract{ sign: Sign::Positive, numer: big("33"), denom: big("1") });
+    }
+
+    #[test]
+    fn test_0240() {
+        // 8/3 = 2.667, closest fraction with denominator <= 2 is 5/2 = 2.5
+        // (closer than both 2/1 and 3/1).
+        let result = best_approx(&Fract{ sign: Sign::Positive, numer: big("8"), denom: big("3") }, &big("2"));
+        assert_eq!(result, Fract{ sign: Sign::Positive, numer: big("5"), denom: big("2") });
+    }
+
+    #[test]
+    fn test_0250() {
+        // An exact fraction within the bound is returned unchanged.
+        let result = best_approx(&Fract{ sign: Sign::Negative, numer: big("3"), denom: big("4") }, &big("100"));
+        assert_eq!(result, Fract{ sign: Sign::Negative, numer: big("3"), denom: big("4") });
+    }
+
+    #[test]
+    fn test_0260() {
+        let result = get_max_denom("0");
+
+        if let Err((ecd, _)) = result {
+            assert_eq!(ecd, 28); // Max denominator must be positive
+        }
+        else {
+            assert!(false);
+        }
+    }
+
+    #[test]
+    fn test_0270() {
+        let result = get_max_denom("abc");
+
+        if let Err((ecd, _)) = result {
+            assert_eq!(ecd, 28); // Can't parse max denominator
+        }
+        else {
+            assert!(false);
+        }
+    }
+
+    #[test]
+    fn test_0280() {
+        let result = get_fract(&"½".to_string());
+        assert_eq!(result, Ok(Fract{ sign: Sign::Positive, numer: big("1"), denom: big("2") }));
+    }
+
+    #[test]
+    fn test_0290() {
+        let result = get_fract(&"-¾".to_string());
+        assert_eq!(result, Ok(Fract{ sign: Sign::Negative, numer: big("3"), denom: big("4") }));
+    }
+
+    #[test]
+    fn test_0300() {
+        // Mixed number: a leading ASCII whole number folded with a vulgar fraction.
+        let result = get_fract(&"1½".to_string());
+        assert_eq!(result, Ok(Fract{ sign: Sign::Positive, numer: big("3"), denom: big("2") }));
+    }
+
+    #[test]
+    fn test_0310() {
+        let result = get_fract(&"³⁄₄".to_string());
+        assert_eq!(result, Ok(Fract{ sign: Sign::Positive, numer: big("3"), denom: big("4") }));
+    }
+
+    #[test]
+    fn test_0320() {
+        // Same mixed-number folding for the superscript/fraction-slash/subscript form.
+        let result = get_fract(&"1³⁄₄".to_string());
+        assert_eq!(result, Ok(Fract{ sign: Sign::Positive, numer: big("7"), denom: big("4") }));
+    }
+
+    #[test]
+    fn test_0330() {
+        let result = get_fract(&"1 1/2".to_string());
+        assert_eq!(result, Ok(Fract{ sign: Sign::Positive, numer: big("3"), denom: big("2") }));
+    }
+
+    #[test]
+    fn test_0340() {
+        let result = get_fract(&"-2 3/4".to_string());
+        assert_eq!(result, Ok(Fract{ sign: Sign::Negative, numer: big("11"), denom: big("4") }));
+    }
+
+    #[test]
+    fn test_0350() {
+        let result = format_mixed(&Fract{ sign: Sign::Positive, numer: big("89"), denom: big("30") });
+        assert_eq!(result, "2 29/30");
+    }
+
+    #[test]
+    fn test_0360() {
+        // Pure integers print without a fractional part.
+        let result = format_mixed(&Fract{ sign: Sign::Positive, numer: big("24"), denom: big("1") });
+        assert_eq!(result, "24");
+    }
+
+    #[test]
+    fn test_0370() {
+        // Zero prints as a plain "0".
+        let result = format_mixed(&Fract{ sign: Sign::Positive, numer: big("0"), denom: big("1") });
+        assert_eq!(result, "0");
+    }
+
+    #[test]
+    fn test_0380() {
+        let result = format_mixed(&Fract{ sign: Sign::Negative, numer: big("11"), denom: big("4") });
+        assert_eq!(result, "-2 3/4");
+    }
+
+    #[test]
+    fn test_0390() {
+        // A proper fraction (no whole part) still prints without a leading "0 ".
+        let result = format_mixed(&Fract{ sign: Sign::Positive, numer: big("1"), denom: big("2") });
+        assert_eq!(result, "1/2");
+    }
+}